@@ -14,6 +14,9 @@ use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use base64::{decode as base64_decode, encode as base64_encode};
+use serde_json;
+
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::convert::TryFrom;
 use std::fmt;
@@ -46,6 +49,371 @@ impl fmt::Display for Strings {
 pub enum SchemaValidationError {
     #[error("Interface `` not defined")]
     A,
+    #[error("Object type `{0}` declares `@key` with an empty `fields` selection")]
+    FederationKeyFieldsEmpty(String),
+    #[error("Object type `{0}` declares `@key` on field `{1}` which does not exist on the type")]
+    FederationKeyFieldUnknown(String, String),
+    #[error("Object type `{0}` has more than one `@key` directive; only a single key is supported")]
+    FederationMultipleKeys(String),
+    #[error(
+        "Object type `{0}` declares a composite `@key(fields: \"{1}\")`; only single-field keys are supported, since entities are resolved by a single `id` column"
+    )]
+    FederationCompositeKeyNotSupported(String, String),
+    #[error(
+        "Object type `{0}` is declared with `@extends`, so its `@key` field `{1}` is resolved by another subgraph and must be marked `@external`"
+    )]
+    FederationExtendsKeyFieldNotExternal(String, String),
+    #[error("`@auth` can only be used on a field of an object or interface type, but was found on `{0}`")]
+    AuthDirectiveMisplaced(String),
+    #[error("`@auth` on `{0}.{1}` does not declare any roles in `requires`")]
+    AuthDirectiveEmptyRoles(String, String),
+    #[error("`@auth` on `{0}.{1}` must set `requires` to a string or a list of strings")]
+    AuthDirectiveMalformed(String, String),
+    #[error("Type `{0}` does not implement interface `{1}`: field `{2}` is missing")]
+    InterfaceFieldMissing(String, String, String),
+    #[error(
+        "Type `{0}` does not implement interface `{1}`: field `{2}` has a type that is not a valid sub-type of the interface's declared type"
+    )]
+    InterfaceFieldTypeMismatch(String, String, String),
+    #[error("Type `{0}` inherits field `{1}` from more than one interface with incompatible types: {2}")]
+    InterfaceFieldConflict(String, String, Strings),
+    #[error("`@import` directive has a malformed `types` or `from` argument")]
+    ImportDirectiveMalformed,
+    #[error("`@import` directive's `from` argument does not reference a subgraph id")]
+    ImportDirectiveInvalidSchemaReference,
+    #[error("{1:?}: {0}")]
+    ImportedTypeUnresolved(SchemaImportError, Pos),
+    #[error("Imported type `{0}` already has a field `{1}`; the local `extend type` collides with it")]
+    ImportedTypeFieldCollision(String, String),
+    #[error("Imported type `{0}` declares `@derivedFrom` on field `{1}` without a `field` argument")]
+    ImportedTypeDerivedFromDangling(String, String),
+    #[error("`extend type {0}` adds field `{1}`, but the imported type `{0}` is a scalar, enum, union, or input object and has no fields to extend")]
+    ImportedTypeExtendHasNoFields(String, String),
+    #[error("`@pagination` directive has a malformed `offset` or `maxFirst` argument")]
+    PaginationDirectiveMalformed,
+    #[error("`@pagination(maxFirst: {0})` is out of bounds; it must be between 1 and 1,000,000")]
+    PaginationMaxFirstOutOfBounds(u32),
+}
+
+/// Where a field on an object type comes from: declared directly on the
+/// type, inherited from a single interface, or inherited from more than
+/// one interface whose declarations of the field disagree on its type.
+/// Interfaces that declare the same field with the *same* type (e.g. two
+/// entity interfaces both requiring `id: ID!`) collapse to `Interface`,
+/// since agreeing on a field is not a conflict.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FieldOrigin {
+    Definition(EntityType),
+    Interface(String),
+    Conflict(Vec<String>),
+}
+
+/// Implements the GraphQL spec's `IsValidImplementation` covariance rules
+/// for non-null and list wrapping: an implementing field's type may add
+/// wrapping that the interface's declared type doesn't require, but may
+/// not remove it. This is intentionally narrower than the full spec rule:
+/// the named-type leaf case requires an exact name match rather than
+/// allowing a concrete type that merely implements the interface's (or
+/// belongs to the union's) declared named type, so covariant object/union
+/// return types are not accepted here.
+fn is_valid_field_subtype(sub_type: &s::Type, super_type: &s::Type) -> bool {
+    use s::Type::*;
+
+    if let NonNullType(super_inner) = super_type {
+        return match sub_type {
+            NonNullType(sub_inner) => is_valid_field_subtype(sub_inner, super_inner),
+            _ => false,
+        };
+    }
+    if let NonNullType(sub_inner) = sub_type {
+        // The implementing field may be stricter (non-null) than an
+        // interface field that only requires nullability.
+        return is_valid_field_subtype(sub_inner, super_type);
+    }
+
+    match (sub_type, super_type) {
+        (ListType(sub_inner), ListType(super_inner)) => {
+            is_valid_field_subtype(sub_inner, super_inner)
+        }
+        (ListType(_), _) | (_, ListType(_)) => false,
+        (NamedType(sub_name), NamedType(super_name)) => sub_name == super_name,
+        _ => false,
+    }
+}
+
+/// The name of the field-level authorization directive, e.g.
+/// `@auth(requires: "admin")` or `@auth(requires: ["admin", "editor"])`.
+const AUTH_DIRECTIVE: &str = "auth";
+
+/// A parsed `@auth` guard: the set of roles/claims that satisfy it. A
+/// request is allowed through if the caller holds at least one of them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuthGuard {
+    pub roles: Vec<String>,
+}
+
+impl AuthGuard {
+    /// Parse the `@auth` directive on `entity_type.field_name`. Returns
+    /// `None` if the field has no `@auth` directive.
+    fn parse(
+        entity_type: &str,
+        field_name: &str,
+        field: &s::Field,
+    ) -> Result<Option<Self>, SchemaValidationError> {
+        let directive = match field.directives.iter().find(|d| d.name == AUTH_DIRECTIVE) {
+            Some(directive) => directive,
+            None => return Ok(None),
+        };
+
+        let malformed = || {
+            SchemaValidationError::AuthDirectiveMalformed(
+                entity_type.to_string(),
+                field_name.to_string(),
+            )
+        };
+
+        let requires = directive.argument("requires").ok_or_else(malformed)?;
+        let roles: Vec<String> = if let Some(role) = requires.as_str() {
+            vec![role.to_string()]
+        } else if let Some(list) = requires.as_list() {
+            list.iter()
+                .map(|v| v.as_str().map(str::to_string).ok_or_else(malformed))
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            return Err(malformed());
+        };
+
+        if roles.iter().any(|role| role.trim().is_empty()) {
+            return Err(malformed());
+        }
+        if roles.is_empty() {
+            return Err(SchemaValidationError::AuthDirectiveEmptyRoles(
+                entity_type.to_string(),
+                field_name.to_string(),
+            ));
+        }
+
+        Ok(Some(AuthGuard { roles }))
+    }
+
+    /// Whether a caller holding `claims` satisfies this guard.
+    pub fn is_satisfied_by(&self, claims: &HashSet<String>) -> bool {
+        self.roles.iter().any(|role| claims.contains(role))
+    }
+}
+
+/// The name of the federation directive that marks the fields that make up
+/// an entity's primary key, e.g. `@key(fields: "id")`.
+const FEDERATION_KEY_DIRECTIVE: &str = "key";
+const FEDERATION_EXTENDS_DIRECTIVE: &str = "extends";
+const FEDERATION_EXTERNAL_DIRECTIVE: &str = "external";
+
+/// The parsed form of a `@key(fields: "...")` directive on an object type,
+/// analogous to how `FulltextDefinition` captures a `@fulltext` directive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FederationKeyDefinition {
+    /// The entity type the `@key` was declared on.
+    pub entity_type: EntityType,
+    /// The fields that make up the key, in declaration order.
+    pub key_fields: Vec<String>,
+    /// Whether the type also carries `@extends`, meaning this subgraph only
+    /// contributes additional fields to a type owned by another subgraph.
+    pub extends: bool,
+}
+
+impl FederationKeyDefinition {
+    /// Parse the `@key`/`@extends`/`@external` directives on `object_type`.
+    /// Returns `None` if the type has no `@key` directive, i.e. it does not
+    /// participate in federation entity resolution.
+    fn parse(object_type: &ObjectType) -> Result<Option<Self>, SchemaValidationError> {
+        let key_directives: Vec<&Directive> = object_type
+            .directives
+            .iter()
+            .filter(|d| d.name == FEDERATION_KEY_DIRECTIVE)
+            .collect();
+
+        if key_directives.is_empty() {
+            return Ok(None);
+        }
+        if key_directives.len() > 1 {
+            return Err(SchemaValidationError::FederationMultipleKeys(
+                object_type.name.clone(),
+            ));
+        }
+
+        let fields_arg = key_directives[0]
+            .argument("fields")
+            .and_then(|v| v.as_str())
+            .unwrap_or("");
+        let key_fields: Vec<String> = fields_arg
+            .split_whitespace()
+            .map(|s| s.to_string())
+            .collect();
+        if key_fields.is_empty() {
+            return Err(SchemaValidationError::FederationKeyFieldsEmpty(
+                object_type.name.clone(),
+            ));
+        }
+        // `entity_key_from_representation` resolves an entity by a single
+        // `id` column; a composite key would have to join several field
+        // values together and could never match a real row, so reject it
+        // here instead of accepting a schema that `_entities` can't serve.
+        if key_fields.len() > 1 {
+            return Err(SchemaValidationError::FederationCompositeKeyNotSupported(
+                object_type.name.clone(),
+                key_fields.join(" "),
+            ));
+        }
+        for field_name in &key_fields {
+            if object_type.field(field_name).is_none() {
+                return Err(SchemaValidationError::FederationKeyFieldUnknown(
+                    object_type.name.clone(),
+                    field_name.clone(),
+                ));
+            }
+        }
+
+        let extends = object_type
+            .directives
+            .iter()
+            .any(|d| d.name == FEDERATION_EXTENDS_DIRECTIVE);
+
+        // An `@extends` type doesn't own its fields: the subgraph that
+        // declared the original type resolves them, and this subgraph only
+        // references the entity by its `@key` in order to contribute
+        // additional fields. Per Federation v1, that means each `@key`
+        // field must also be marked `@external` here.
+        if extends {
+            for field_name in &key_fields {
+                let field = object_type
+                    .field(field_name)
+                    .expect("already checked to exist above");
+                if !is_federation_external_field(field) {
+                    return Err(SchemaValidationError::FederationExtendsKeyFieldNotExternal(
+                        object_type.name.clone(),
+                        field_name.clone(),
+                    ));
+                }
+            }
+        }
+
+        Ok(Some(FederationKeyDefinition {
+            entity_type: EntityType::from(object_type.name.as_str()),
+            key_fields,
+            extends,
+        }))
+    }
+}
+
+/// Returns true if `field` is marked `@external`, i.e. it is only present on
+/// the type for the purpose of being referenced from `@key`/`@requires` and
+/// is resolved by another subgraph.
+fn is_federation_external_field(field: &s::Field) -> bool {
+    field
+        .directives
+        .iter()
+        .any(|d| d.name == FEDERATION_EXTERNAL_DIRECTIVE)
+}
+
+/// The name of the Relay-style interface every entity object type implements
+/// in the generated API schema.
+pub const NODE_INTERFACE_NAME: &str = "Node";
+
+/// The current version tag embedded in every opaque `nodeId`. Bumping this
+/// lets a future encoding be distinguished from today's without guessing.
+const NODE_ID_VERSION: &str = "v1";
+
+/// An opaque, globally unique identifier for an entity, as used by the
+/// Relay `Node` interface. Encodes to (and decodes from) a base64 payload
+/// of the form `["v1", "<EntityType>", "<id>"]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NodeId {
+    pub entity_type: EntityType,
+    pub entity_id: String,
+}
+
+impl NodeId {
+    pub fn new(entity_type: EntityType, entity_id: String) -> Self {
+        NodeId {
+            entity_type,
+            entity_id,
+        }
+    }
+
+    /// Encode this id as the opaque, versioned string handed out to clients.
+    pub fn encode(&self) -> String {
+        let payload =
+            serde_json::json!([NODE_ID_VERSION, self.entity_type.as_str(), self.entity_id]);
+        base64_encode(payload.to_string())
+    }
+
+    /// Decode a client-supplied `id: ID!` back into a `NodeId`, validating
+    /// that the entity type is known to `schema` and that its id column
+    /// type can represent `entity_id` by reusing `Schema::id_value`.
+    pub fn decode(schema: &Schema, node_id: &str) -> Result<Self, Error> {
+        let bytes = base64_decode(node_id).context("nodeId is not valid base64")?;
+        let payload: serde_json::Value =
+            serde_json::from_slice(&bytes).context("nodeId does not contain a JSON payload")?;
+        let parts = payload
+            .as_array()
+            .ok_or_else(|| anyhow!("nodeId payload must be a JSON array"))?;
+        let [version, entity_type, entity_id] = <[serde_json::Value; 3]>::try_from(parts.clone())
+            .map_err(|_| anyhow!("nodeId payload must have exactly 3 elements"))?;
+
+        let version = version
+            .as_str()
+            .ok_or_else(|| anyhow!("nodeId version must be a string"))?;
+        if version != NODE_ID_VERSION {
+            return Err(anyhow!("unsupported nodeId version `{}`", version));
+        }
+
+        let entity_type = entity_type
+            .as_str()
+            .ok_or_else(|| anyhow!("nodeId entity type must be a string"))?;
+        let entity_id = entity_id
+            .as_str()
+            .ok_or_else(|| anyhow!("nodeId entity id must be a string"))?
+            .to_string();
+
+        // `id_value` assumes the type has an `id` field, so reject object
+        // types that aren't entities (or don't exist) before calling it.
+        if schema
+            .document
+            .get_object_type_definition(entity_type)
+            .and_then(|t| t.field("id"))
+            .is_none()
+        {
+            return Err(anyhow!("nodeId names unknown entity type `{}`", entity_type));
+        }
+
+        let node_id = NodeId {
+            entity_type: EntityType::from(entity_type),
+            entity_id,
+        };
+
+        // Reuses the id column type check that already guards entity
+        // lookups, so a nodeId can't be forged for an id shape the entity
+        // doesn't actually use.
+        schema.id_value(&node_id.clone().into_entity_key())?;
+
+        Ok(node_id)
+    }
+
+    pub fn into_entity_key(self) -> EntityKey {
+        EntityKey {
+            entity_type: self.entity_type,
+            entity_id: self.entity_id,
+        }
+    }
+}
+
+impl From<&EntityKey> for NodeId {
+    fn from(key: &EntityKey) -> Self {
+        NodeId {
+            entity_type: key.entity_type.clone(),
+            entity_id: key.entity_id.clone(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -144,24 +512,51 @@ pub struct ImportedType {
 }
 
 impl ImportedType {
+    /// Parse one entry of the `types` argument of an `@import` directive.
+    /// Accepts either a bare string (`"Thing"`) or an object with an
+    /// explicit alias (`{name: "Thing", as: "Stuff"}`).
     fn parse(type_import: &Value) -> Option<Self> {
-        None
+        match type_import {
+            Value::String(name) => Some(ImportedType {
+                name: name.clone(),
+                alias: name.clone(),
+                explicit: false,
+            }),
+            Value::Object(map) => {
+                let name = map.get("name")?.as_str()?.to_string();
+                match map.get("as") {
+                    Some(alias) => Some(ImportedType {
+                        name,
+                        alias: alias.as_str()?.to_string(),
+                        explicit: true,
+                    }),
+                    None => Some(ImportedType {
+                        alias: name.clone(),
+                        name,
+                        explicit: false,
+                    }),
+                }
+            }
+            _ => None,
+        }
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// A reference to another subgraph's deployed schema, as named by the
+/// `from: { id: "..." }` argument of an `@import` directive.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct SchemaReference {
-    subgraph: (),
+    subgraph: String,
 }
 
 impl fmt::Display for SchemaReference {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        write!(f, "{}", 0)
+        write!(f, "{}", self.subgraph)
     }
 }
 
 impl SchemaReference {
-    fn new(subgraph: ()) -> Self {
+    fn new(subgraph: String) -> Self {
         SchemaReference { subgraph }
     }
 
@@ -169,15 +564,22 @@ impl SchemaReference {
         &self,
         store: Arc<S>,
     ) -> Result<Arc<Schema>, SchemaImportError> {
-        store
-            .input_schema(todo!())
-            .map_err(|_| SchemaImportError::ImportedSchemaNotFound(self.clone()))
+        // `input_schema` returning `Err` means the lookup itself failed,
+        // i.e. `self.subgraph` doesn't name a deployed subgraph at all;
+        // `Ok(None)` means the subgraph is deployed but hasn't recorded an
+        // input schema. Report those as the two distinct errors they are
+        // instead of collapsing both into "schema not found".
+        match store.input_schema(&self.subgraph) {
+            Ok(Some(schema)) => Ok(schema),
+            Ok(None) => Err(SchemaImportError::ImportedSchemaNotFound(self.clone())),
+            Err(_) => Err(SchemaImportError::ImportedSubgraphNotFound(self.clone())),
+        }
     }
 
     fn parse(value: &Value) -> Option<Self> {
         match value {
             Value::Object(map) => match map.get("id") {
-                Some(Value::String(id)) => None,
+                Some(Value::String(id)) => Some(SchemaReference::new(id.clone())),
                 _ => None,
             },
             _ => None,
@@ -185,6 +587,92 @@ impl SchemaReference {
     }
 }
 
+/// The name of the cross-subgraph import directive, e.g.
+/// `@import(types: ["Thing", {name: "Other", as: "Alias"}], from: {id: "Qm..."})`.
+const IMPORT_DIRECTIVE: &str = "import";
+const DERIVED_FROM_DIRECTIVE: &str = "derivedFrom";
+
+/// The name of the schema-wide directive that configures pagination for
+/// collection fields, e.g. `@pagination(offset: true, maxFirst: 1000)`.
+/// Declared on the `_Schema_` type, alongside `@import` and `@subgraphId`.
+const PAGINATION_DIRECTIVE: &str = "pagination";
+
+/// The `first` upper bound used when a schema doesn't declare its own via
+/// `@pagination(maxFirst: ...)`.
+pub const DEFAULT_MAX_FIRST: u32 = 1000;
+
+/// Schema-wide pagination settings. `offset`-based pagination is opt-in so
+/// existing subgraphs keep behaving exactly as they did before this was
+/// added; `max_first` always applies and defaults to `DEFAULT_MAX_FIRST`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaginationConfig {
+    pub offset_enabled: bool,
+    pub max_first: u32,
+}
+
+impl Default for PaginationConfig {
+    fn default() -> Self {
+        PaginationConfig {
+            offset_enabled: false,
+            max_first: DEFAULT_MAX_FIRST,
+        }
+    }
+}
+
+impl PaginationConfig {
+    /// Parse the `@pagination` directive on the `_Schema_` type, if any.
+    fn parse(directive: &Directive) -> Result<Self, SchemaValidationError> {
+        let offset_enabled = match directive.argument("offset") {
+            Some(Value::Boolean(b)) => *b,
+            Some(_) => return Err(SchemaValidationError::PaginationDirectiveMalformed),
+            None => false,
+        };
+
+        let max_first = match directive.argument("maxFirst") {
+            Some(Value::Int(n)) => n
+                .as_i64()
+                .and_then(|n| u32::try_from(n).ok())
+                .ok_or(SchemaValidationError::PaginationDirectiveMalformed)?,
+            Some(_) => return Err(SchemaValidationError::PaginationDirectiveMalformed),
+            None => DEFAULT_MAX_FIRST,
+        };
+
+        if max_first == 0 || max_first > 1_000_000 {
+            return Err(SchemaValidationError::PaginationMaxFirstOutOfBounds(
+                max_first,
+            ));
+        }
+
+        Ok(PaginationConfig {
+            offset_enabled,
+            max_first,
+        })
+    }
+}
+
+/// A single parsed `@import` directive: the types it brings in, aliased as
+/// needed, and the subgraph they're imported from.
+struct ImportDirective {
+    types: Vec<ImportedType>,
+    schema_ref: SchemaReference,
+}
+
+impl ImportDirective {
+    fn parse(directive: &Directive) -> Option<Self> {
+        if directive.name != IMPORT_DIRECTIVE {
+            return None;
+        }
+        let types = directive
+            .argument("types")?
+            .as_list()?
+            .iter()
+            .filter_map(ImportedType::parse)
+            .collect();
+        let schema_ref = SchemaReference::parse(directive.argument("from")?)?;
+        Some(ImportDirective { types, schema_ref })
+    }
+}
+
 #[derive(Debug)]
 pub struct ApiSchema {
     schema: Schema,
@@ -193,6 +681,10 @@ pub struct ApiSchema {
     pub query_type: Arc<ObjectType>,
     pub subscription_type: Option<Arc<ObjectType>>,
     object_types: HashMap<String, Arc<ObjectType>>,
+
+    // Entity types that declare a federation `@key` and therefore take part
+    // in `_entities` resolution, keyed by the `__typename` they resolve.
+    federation_keys: BTreeMap<String, FederationKeyDefinition>,
 }
 
 impl ApiSchema {
@@ -201,10 +693,31 @@ impl ApiSchema {
     ///
     /// In addition, the API schema has an introspection schema mixed into
     /// `api_schema`. In particular, the `Query` type has fields called
-    /// `__schema` and `__type`
-    pub fn from_api_schema(mut api_schema: Schema) -> Result<Self, anyhow::Error> {
+    /// `__schema` and `__type`.
+    ///
+    /// When `expose_federation` is set, the schema also gains the Apollo
+    /// Federation v1 root fields (`_service`, `_entities`) so the subgraph
+    /// can be composed into a federated supergraph.
+    ///
+    /// If the subgraph's schema set `@pagination(offset: true)`, every
+    /// collection field also gains an `offset: Int` argument, so clients
+    /// can actually make use of `Schema::validate_pagination_window`.
+    pub fn from_api_schema(
+        mut api_schema: Schema,
+        expose_federation: bool,
+    ) -> Result<Self, anyhow::Error> {
         add_introspection_schema(&mut api_schema.document);
 
+        let federation_keys = collect_federation_keys(&api_schema.document)?;
+
+        if expose_federation {
+            add_federation_schema(&mut api_schema.document, &federation_keys);
+        }
+
+        add_node_interface_schema(&mut api_schema.document);
+
+        add_pagination_arguments(&mut api_schema.document, &api_schema.pagination);
+
         let query_type = api_schema
             .document
             .get_root_query_type()
@@ -229,9 +742,102 @@ impl ApiSchema {
             query_type: Arc::new(query_type),
             subscription_type,
             object_types,
+            federation_keys,
         })
     }
 
+    /// Render the SDL for this subgraph, for use by the federation
+    /// `_service { sdl }` field.
+    pub fn sdl(&self) -> String {
+        self.schema.document.to_string()
+    }
+
+    /// Build the `EntityKey` that a federation `representations` entry
+    /// refers to, by looking up the `@key` fields declared for the entity's
+    /// `__typename`.
+    pub fn entity_key_from_representation(
+        &self,
+        representation: &BTreeMap<String, Value>,
+    ) -> Result<EntityKey, Error> {
+        let typename = representation
+            .get("__typename")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow!("federation representation is missing `__typename`"))?;
+
+        let key_def = self
+            .federation_keys
+            .get(typename)
+            .ok_or_else(|| anyhow!("type `{}` does not declare a federation `@key`", typename))?;
+
+        // `FederationKeyDefinition::parse` rejects composite `@key`s, so
+        // entities are always resolved by exactly one field's value.
+        let field = key_def
+            .key_fields
+            .first()
+            .expect("FederationKeyDefinition::parse never returns an empty key_fields");
+        let id = representation
+            .get(field)
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                anyhow!(
+                    "representation for `{}` is missing key field `{}`",
+                    typename,
+                    field
+                )
+            })?
+            .to_string();
+
+        Ok(EntityKey {
+            entity_type: key_def.entity_type.clone(),
+            entity_id: id,
+        })
+    }
+
+    /// Decode a Relay `node(id: ...)` argument into the `EntityKey` it
+    /// refers to.
+    pub fn entity_key_from_node_id(&self, node_id: &str) -> Result<EntityKey, Error> {
+        NodeId::decode(&self.schema, node_id).map(NodeId::into_entity_key)
+    }
+
+    /// Encode an `EntityKey` as the opaque `id` field of the `Node`
+    /// interface.
+    pub fn node_id(&self, key: &EntityKey) -> String {
+        NodeId::from(key).encode()
+    }
+
+    /// The `@auth` guard declared on `entity_type.field_name`, if any. The
+    /// query executor should consult this before resolving the field.
+    pub fn auth_guard(&self, entity_type: &EntityType, field_name: &str) -> Option<&AuthGuard> {
+        self.schema
+            .auth_guards
+            .get(&(entity_type.clone(), field_name.to_string()))
+    }
+
+    /// Check that `claims` satisfy the `@auth` guard on
+    /// `entity_type.field_name`, if one is declared. Returns an error
+    /// naming the field when the guard is not satisfied.
+    pub fn check_field_authorization(
+        &self,
+        entity_type: &EntityType,
+        field_name: &str,
+        claims: &HashSet<String>,
+    ) -> Result<(), Error> {
+        match self.auth_guard(entity_type, field_name) {
+            Some(guard) if !guard.is_satisfied_by(claims) => Err(anyhow!(
+                "not authorized to access `{}.{}`: requires one of [{}]",
+                entity_type,
+                field_name,
+                guard.roles.join(", ")
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// The `first`/`offset` pagination bounds configured for this schema.
+    pub fn pagination(&self) -> &PaginationConfig {
+        &self.schema.pagination
+    }
+
     pub fn document(&self) -> &s::Document {
         &self.schema.document
     }
@@ -253,6 +859,16 @@ impl ApiSchema {
         self.schema.interfaces_for_type(type_name)
     }
 
+    /// Where `field_name` on `type_name` was declared: directly on the
+    /// type, inherited from an interface, or inherited from conflicting
+    /// interfaces. Lets query planning tell whether a selected field came
+    /// from the concrete type or an interface.
+    pub fn field_origin(&self, type_name: &EntityType, field_name: &str) -> Option<&FieldOrigin> {
+        self.schema
+            .field_origins
+            .get(&(type_name.clone(), field_name.to_string()))
+    }
+
     /// Return an `Arc` around the `ObjectType` from our internal cache
     ///
     /// # Panics
@@ -345,6 +961,200 @@ lazy_static! {
 
 fn add_introspection_schema(schema: &mut Document) {}
 
+/// Scan `document` for object types carrying a `@key` directive and return
+/// the federation key definitions, keyed by `__typename`.
+fn collect_federation_keys(
+    document: &Document,
+) -> Result<BTreeMap<String, FederationKeyDefinition>, anyhow::Error> {
+    let mut keys = BTreeMap::new();
+    for obj_type in document.get_object_type_definitions() {
+        if let Some(key_def) = FederationKeyDefinition::parse(obj_type)? {
+            keys.insert(obj_type.name.clone(), key_def);
+        }
+    }
+    Ok(keys)
+}
+
+/// Splice the Apollo Federation v1 root types and fields into `document`:
+/// the `_Any` scalar, the `_Entity` union of every `@key`-annotated object
+/// type, the `_Service` type, and the `_service`/`_entities` root fields.
+fn add_federation_schema(
+    document: &mut Document,
+    federation_keys: &BTreeMap<String, FederationKeyDefinition>,
+) {
+    let entity_members = federation_keys.keys().cloned().collect::<Vec<_>>().join(" | ");
+    let entity_union = if entity_members.is_empty() {
+        // An empty union isn't valid GraphQL; fall back to a union with no
+        // resolvable members rather than failing schema construction.
+        "union _Entity = _Service".to_string()
+    } else {
+        format!("union _Entity = {}", entity_members)
+    };
+
+    let federation_sdl = format!(
+        "scalar _Any\n\
+         {entity_union}\n\
+         type _Service {{ sdl: String }}\n\
+         extend type Query {{\n\
+         \x20 _service: _Service!\n\
+         \x20 _entities(representations: [_Any!]!): [_Entity]!\n\
+         }}\n",
+        entity_union = entity_union,
+    );
+
+    let federation_doc = graphql_parser::parse_schema(&federation_sdl)
+        .expect("generated federation SDL is valid GraphQL")
+        .into_static();
+
+    for def in federation_doc.definitions {
+        match def {
+            // Merge the `_service`/`_entities` fields onto the existing
+            // `Query` type instead of adding a second `Query` definition.
+            Definition::TypeExtension(s::TypeExtension::Object(ext)) => {
+                for existing in document.definitions.iter_mut() {
+                    if let Definition::TypeDefinition(TypeDefinition::Object(query)) = existing {
+                        if query.name == ext.name {
+                            query.fields.extend(ext.fields.clone());
+                        }
+                    }
+                }
+            }
+            other => document.definitions.push(other),
+        }
+    }
+}
+
+/// Add the Relay `Node` interface to `document`, make every entity object
+/// type implement it, and expose `node`/`nodes` root fields on `Query`.
+fn add_node_interface_schema(document: &mut Document) {
+    const NODE_SCHEMA: &str = "\
+        interface Node { id: ID! }\n\
+        extend type Query {\n\
+        \x20 node(id: ID!): Node\n\
+        \x20 nodes(ids: [ID!]!): [Node]!\n\
+        }\n";
+
+    let node_doc = graphql_parser::parse_schema(NODE_SCHEMA)
+        .expect("generated Node SDL is valid GraphQL")
+        .into_static();
+
+    for def in document.definitions.iter_mut() {
+        if let Definition::TypeDefinition(TypeDefinition::Object(obj_type)) = def {
+            // Only entities carry an `id` field; the introspection/meta
+            // types injected elsewhere don't implement `Node`.
+            if obj_type.field("id").is_some()
+                && !obj_type
+                    .implements_interfaces
+                    .iter()
+                    .any(|i| i == NODE_INTERFACE_NAME)
+            {
+                obj_type
+                    .implements_interfaces
+                    .push(NODE_INTERFACE_NAME.to_string());
+            }
+        }
+    }
+
+    for def in node_doc.definitions {
+        match def {
+            Definition::TypeExtension(s::TypeExtension::Object(ext)) => {
+                for existing in document.definitions.iter_mut() {
+                    if let Definition::TypeDefinition(TypeDefinition::Object(query)) = existing {
+                        if query.name == ext.name {
+                            query.fields.extend(ext.fields.clone());
+                        }
+                    }
+                }
+            }
+            other => document.definitions.push(other),
+        }
+    }
+}
+
+/// Build a single `offset: Int` argument by parsing a throwaway SDL
+/// fragment and pulling the argument back out, the same way
+/// `add_federation_schema`/`add_node_interface_schema` build whole types:
+/// going through the parser sidesteps hand-assembling an `InputValue` AST
+/// node field by field.
+fn offset_argument() -> s::InputValue {
+    const OFFSET_ARG_SDL: &str = "type _PaginationOffsetArg { f(offset: Int): Boolean }";
+
+    let doc = graphql_parser::parse_schema(OFFSET_ARG_SDL)
+        .expect("generated offset-argument SDL is valid GraphQL")
+        .into_static();
+
+    for def in doc.definitions {
+        if let Definition::TypeDefinition(TypeDefinition::Object(t)) = def {
+            return t.fields.into_iter().next().unwrap().arguments.into_iter().next().unwrap();
+        }
+    }
+    unreachable!("the generated SDL always has exactly one field with one argument")
+}
+
+/// The named type at the bottom of a (possibly wrapped) list type, e.g.
+/// `Some("Token")` for `[Token!]!`, or `None` if `field_type` isn't a list
+/// at all (`is_valid_field_subtype` has the analogous non-null/list
+/// wrapping rules for covariance checks).
+fn list_item_type_name(field_type: &s::Type) -> Option<&str> {
+    match field_type {
+        s::Type::NonNullType(inner) => list_item_type_name(inner),
+        s::Type::ListType(inner) => named_type_name(inner),
+        s::Type::NamedType(_) => None,
+    }
+}
+
+fn named_type_name(field_type: &s::Type) -> Option<&str> {
+    match field_type {
+        s::Type::NonNullType(inner) => named_type_name(inner),
+        s::Type::NamedType(name) => Some(name.as_str()),
+        s::Type::ListType(_) => None,
+    }
+}
+
+/// Add an explicit `offset: Int` argument to every "collection field" in
+/// `document` — an object/interface field whose return type is a list of
+/// some object type defined in the schema — so that clients can actually
+/// request offset pagination and `Schema::validate_pagination_window` has
+/// an argument to validate. A no-op unless `@pagination(offset: true)`
+/// was set: schemas that leave offset pagination at its default stay
+/// exactly as they were, with no `offset` argument added anywhere.
+///
+/// Plumbing fields injected elsewhere (`node`/`nodes`, `_entities`) return
+/// lists of the `Node` interface or the `_Entity` union rather than of an
+/// object type defined in the schema, so they're left alone here.
+fn add_pagination_arguments(document: &mut Document, pagination: &PaginationConfig) {
+    if !pagination.offset_enabled {
+        return;
+    }
+
+    let object_type_names: HashSet<&str> = document
+        .definitions
+        .iter()
+        .filter_map(|def| match def {
+            Definition::TypeDefinition(TypeDefinition::Object(t)) => Some(t.name.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    let offset_argument = offset_argument();
+
+    for def in document.definitions.iter_mut() {
+        let fields = match def {
+            Definition::TypeDefinition(TypeDefinition::Object(t)) => &mut t.fields,
+            Definition::TypeDefinition(TypeDefinition::Interface(t)) => &mut t.fields,
+            _ => continue,
+        };
+        for field in fields.iter_mut() {
+            let is_collection_field = list_item_type_name(&field.field_type)
+                .map(|name| object_type_names.contains(name))
+                .unwrap_or(false);
+            if is_collection_field && !field.arguments.iter().any(|arg| arg.name == "offset") {
+                field.arguments.push(offset_argument.clone());
+            }
+        }
+    }
+}
+
 /// A validated and preprocessed GraphQL schema for a subgraph.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Schema {
@@ -356,6 +1166,18 @@ pub struct Schema {
 
     // Maps an interface name to the list of entities that implement it.
     pub types_for_interface: BTreeMap<EntityType, Vec<ObjectType>>,
+
+    // Maps a (type, field) pair to the `@auth` guard declared on that
+    // field, if any.
+    pub auth_guards: BTreeMap<(EntityType, String), AuthGuard>,
+
+    // Maps a (type, field) pair to where that field was first declared:
+    // directly on the type, or inherited from an interface.
+    pub field_origins: BTreeMap<(EntityType, String), FieldOrigin>,
+
+    // `first`/`offset` windowing limits, configured via `@pagination` on
+    // the `_Schema_` type. Defaults to offset pagination disabled.
+    pub pagination: PaginationConfig,
 }
 
 impl Schema {
@@ -416,9 +1238,31 @@ impl Schema {
         &self,
         store: Arc<S>,
         schemas: &mut HashMap<SchemaReference, Arc<Schema>>,
-        visit_log: &mut HashSet<()>,
+        visit_log: &mut HashSet<SchemaReference>,
     ) -> Vec<SchemaImportError> {
-        vec![]
+        let mut errors = vec![];
+
+        for schema_ref in self.imported_schemas() {
+            // Breaks cycles: `A imports B imports A` resolves `B` once,
+            // not infinitely.
+            if !visit_log.insert(schema_ref.clone()) {
+                continue;
+            }
+
+            match schema_ref.resolve(store.clone()) {
+                Ok(imported_schema) => {
+                    errors.extend(imported_schema.resolve_import_graph(
+                        store.clone(),
+                        schemas,
+                        visit_log,
+                    ));
+                    schemas.insert(schema_ref, imported_schema);
+                }
+                Err(e) => errors.push(e),
+            }
+        }
+
+        errors
     }
 
     pub fn collect_interfaces(
@@ -427,22 +1271,313 @@ impl Schema {
         (
             BTreeMap<EntityType, Vec<InterfaceType>>,
             BTreeMap<EntityType, Vec<ObjectType>>,
+            BTreeMap<(EntityType, String), FieldOrigin>,
         ),
         SchemaValidationError,
     > {
-        todo!()
+        let interface_defs: Vec<&InterfaceType> = document
+            .definitions
+            .iter()
+            .filter_map(|d| match d {
+                Definition::TypeDefinition(TypeDefinition::Interface(i)) => Some(i),
+                _ => None,
+            })
+            .collect();
+
+        let mut interfaces_for_type: BTreeMap<EntityType, Vec<InterfaceType>> = BTreeMap::new();
+        let mut types_for_interface: BTreeMap<EntityType, Vec<ObjectType>> = interface_defs
+            .iter()
+            .map(|i| (EntityType::from(i.name.as_str()), vec![]))
+            .collect();
+        let mut field_origins: BTreeMap<(EntityType, String), FieldOrigin> = BTreeMap::new();
+
+        for obj_type in document.get_object_type_definitions() {
+            let entity_type = EntityType::from(obj_type.name.as_str());
+
+            let implemented: Vec<&InterfaceType> = interface_defs
+                .iter()
+                .filter(|i| obj_type.implements_interfaces.iter().any(|n| n == &i.name))
+                .cloned()
+                .collect();
+
+            if !implemented.is_empty() {
+                interfaces_for_type.insert(
+                    entity_type.clone(),
+                    implemented.iter().map(|i| (*i).clone()).collect(),
+                );
+            }
+            for interface in &implemented {
+                types_for_interface
+                    .entry(EntityType::from(interface.name.as_str()))
+                    .or_insert_with(Vec::new)
+                    .push(obj_type.clone());
+            }
+
+            for field in &obj_type.fields {
+                let declaring: Vec<&InterfaceType> = implemented
+                    .iter()
+                    .filter(|i| i.fields.iter().any(|f| f.name == field.name))
+                    .cloned()
+                    .collect();
+
+                // A field declared by two or more interfaces (e.g. every
+                // entity interface requiring `id: ID!`) is ordinary,
+                // legal GraphQL as long as those interfaces agree on the
+                // field's type; `Conflict` is reserved for the case where
+                // they don't, and is what `validate_interface_implementation`
+                // rejects. Interfaces that agree collapse to `Interface` so
+                // that agreement is never mistaken for a conflict.
+                let origin = match declaring.as_slice() {
+                    [] => FieldOrigin::Definition(entity_type.clone()),
+                    [single] => FieldOrigin::Interface(single.name.clone()),
+                    many => {
+                        let field_type = |i: &InterfaceType| {
+                            &i.fields.iter().find(|f| f.name == field.name).unwrap().field_type
+                        };
+                        let first_type = field_type(many[0]);
+                        if many.iter().all(|i| field_type(i) == first_type) {
+                            FieldOrigin::Interface(many[0].name.clone())
+                        } else {
+                            FieldOrigin::Conflict(many.iter().map(|i| i.name.clone()).collect())
+                        }
+                    }
+                };
+                field_origins.insert((entity_type.clone(), field.name.clone()), origin);
+            }
+        }
+
+        Ok((interfaces_for_type, types_for_interface, field_origins))
     }
 
     pub fn parse(raw: &str, id: ()) -> Result<Self, Error> {
         todo!()
     }
 
+    /// Parse every `@auth` directive in `document` into a guard, keyed by
+    /// the `(type, field)` pair it was declared on. `@auth` is only valid
+    /// on fields of object and interface types.
+    pub fn collect_auth_guards(
+        document: &s::Document,
+    ) -> Result<BTreeMap<(EntityType, String), AuthGuard>, SchemaValidationError> {
+        let mut guards = BTreeMap::new();
+
+        for def in &document.definitions {
+            let (type_name, fields): (&str, &[s::Field]) = match def {
+                Definition::TypeDefinition(TypeDefinition::Object(t)) => (&t.name, &t.fields),
+                Definition::TypeDefinition(TypeDefinition::Interface(t)) => (&t.name, &t.fields),
+                _ => continue,
+            };
+
+            for field in fields {
+                if let Some(guard) = AuthGuard::parse(type_name, &field.name, field)? {
+                    guards.insert((EntityType::from(type_name), field.name.clone()), guard);
+                }
+            }
+        }
+
+        // `@auth` is only valid on a *field* of an object or interface
+        // type, never on the type definition itself (object, interface)
+        // or on any other kind of definition (scalar, enum, union, input
+        // object, ...): flag the first offending type.
+        for def in &document.definitions {
+            let offender: Option<&str> = match def {
+                Definition::TypeDefinition(TypeDefinition::Object(t))
+                    if t.directives.iter().any(|d| d.name == AUTH_DIRECTIVE) =>
+                {
+                    Some(&t.name)
+                }
+                Definition::TypeDefinition(TypeDefinition::Interface(t))
+                    if t.directives.iter().any(|d| d.name == AUTH_DIRECTIVE) =>
+                {
+                    Some(&t.name)
+                }
+                Definition::TypeDefinition(TypeDefinition::Scalar(t))
+                    if t.directives.iter().any(|d| d.name == AUTH_DIRECTIVE) =>
+                {
+                    Some(&t.name)
+                }
+                Definition::TypeDefinition(TypeDefinition::Union(t))
+                    if t.directives.iter().any(|d| d.name == AUTH_DIRECTIVE) =>
+                {
+                    Some(&t.name)
+                }
+                Definition::TypeDefinition(TypeDefinition::Enum(t))
+                    if t.directives.iter().any(|d| d.name == AUTH_DIRECTIVE) =>
+                {
+                    Some(&t.name)
+                }
+                Definition::TypeDefinition(TypeDefinition::InputObject(t))
+                    if t.directives.iter().any(|d| d.name == AUTH_DIRECTIVE) =>
+                {
+                    Some(&t.name)
+                }
+                _ => None,
+            };
+            if let Some(name) = offender {
+                return Err(SchemaValidationError::AuthDirectiveMisplaced(
+                    name.to_string(),
+                ));
+            }
+        }
+
+        Ok(guards)
+    }
+
+    /// Every `@import` directive on the schema's `_Schema_` type, in
+    /// declaration order.
+    fn import_directives(&self) -> Vec<&Directive> {
+        self.subgraph_schema_object_type()
+            .map(|t| {
+                t.directives
+                    .iter()
+                    .filter(|d| d.name == IMPORT_DIRECTIVE)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     fn imported_types(&self) -> HashMap<ImportedType, SchemaReference> {
-        todo!()
+        self.import_directives()
+            .into_iter()
+            .filter_map(ImportDirective::parse)
+            .flat_map(|import| {
+                let schema_ref = import.schema_ref;
+                import
+                    .types
+                    .into_iter()
+                    .map(move |t| (t, schema_ref.clone()))
+            })
+            .collect()
     }
 
     pub fn imported_schemas(&self) -> Vec<SchemaReference> {
-        vec![]
+        self.imported_types()
+            .into_values()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Materialize every `@import`ed type into this schema's document: copy
+    /// the type definition from the referenced subgraph, rename it to its
+    /// alias, and merge in any `extend type <alias> { ... }` fields the
+    /// importing schema declared for it. Types whose source schema
+    /// couldn't be resolved are skipped; `validate_imported_types` already
+    /// reports that as an error.
+    pub fn add_imported_types(
+        &mut self,
+        schemas: &HashMap<SchemaReference, Arc<Schema>>,
+    ) -> Vec<SchemaValidationError> {
+        let imports = self.imported_types();
+        let mut errors = vec![];
+
+        for (imported_type, schema_ref) in imports {
+            let source_type = match schemas
+                .get(&schema_ref)
+                .and_then(|schema| schema.document.get_named_type(&imported_type.name))
+            {
+                Some(t) => t.clone(),
+                None => continue,
+            };
+
+            match self.merge_imported_type(source_type, &imported_type.alias) {
+                Ok(merged) => self
+                    .document
+                    .definitions
+                    .push(Definition::TypeDefinition(merged)),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        errors
+    }
+
+    /// Rename `source_type` to `alias` and fold in any local
+    /// `extend type <alias> { ... }` fields, rejecting collisions with the
+    /// imported type's own fields and `@derivedFrom` directives that don't
+    /// name a target field. Object and interface types can be extended
+    /// this way; an `extend type` naming a scalar, enum, union, or input
+    /// object import is an error, since those kinds have no fields.
+    fn merge_imported_type(
+        &mut self,
+        mut source_type: TypeDefinition,
+        alias: &str,
+    ) -> Result<TypeDefinition, SchemaValidationError> {
+        let mut extension_fields = vec![];
+        self.document.definitions.retain(|def| match def {
+            Definition::TypeExtension(s::TypeExtension::Object(ext)) if ext.name == alias => {
+                extension_fields.extend(ext.fields.clone());
+                false
+            }
+            _ => true,
+        });
+
+        if let TypeDefinition::Object(obj) = &mut source_type {
+            obj.name = alias.to_string();
+            Self::merge_extension_fields(alias, &mut obj.fields, extension_fields)?;
+        } else if let TypeDefinition::Interface(iface) = &mut source_type {
+            iface.name = alias.to_string();
+            Self::merge_extension_fields(alias, &mut iface.fields, extension_fields)?;
+        } else {
+            match &mut source_type {
+                TypeDefinition::Scalar(t) => t.name = alias.to_string(),
+                TypeDefinition::Enum(t) => t.name = alias.to_string(),
+                TypeDefinition::Union(t) => t.name = alias.to_string(),
+                TypeDefinition::InputObject(t) => t.name = alias.to_string(),
+                _ => {}
+            }
+            if let Some(field) = extension_fields.into_iter().next() {
+                // Scalars, enums, unions, and input objects have no fields to
+                // extend; rather than silently dropping the `extend type`
+                // block pulled out of the document above, reject it.
+                return Err(SchemaValidationError::ImportedTypeExtendHasNoFields(
+                    alias.to_string(),
+                    field.name,
+                ));
+            }
+        }
+
+        Ok(source_type)
+    }
+
+    /// Fold `extension_fields` into `fields`, rejecting a name collision
+    /// with an existing field and a `@derivedFrom` that doesn't name a
+    /// target field.
+    fn merge_extension_fields(
+        alias: &str,
+        fields: &mut Vec<s::Field>,
+        extension_fields: Vec<s::Field>,
+    ) -> Result<(), SchemaValidationError> {
+        for field in extension_fields {
+            if fields.iter().any(|f| f.name == field.name) {
+                return Err(SchemaValidationError::ImportedTypeFieldCollision(
+                    alias.to_string(),
+                    field.name,
+                ));
+            }
+
+            let has_dangling_derived_from = field
+                .directives
+                .iter()
+                .find(|d| d.name == DERIVED_FROM_DIRECTIVE)
+                .map(|derived_from| {
+                    derived_from
+                        .argument("field")
+                        .and_then(|v| v.as_str())
+                        .map_or(true, |s| s.is_empty())
+                })
+                .unwrap_or(false);
+            if has_dangling_derived_from {
+                return Err(SchemaValidationError::ImportedTypeDerivedFromDangling(
+                    alias.to_string(),
+                    field.name,
+                ));
+            }
+
+            fields.push(field);
+        }
+
+        Ok(())
     }
 
     pub fn name_argument_value_from_directive(directive: &Directive) -> Value {
@@ -465,11 +1600,46 @@ impl Schema {
     // Adds a @subgraphId(id: ...) directive to object/interface/enum types in the schema.
     pub fn add_subgraph_id_directives(&mut self, id: ()) {}
 
+    /// `schemas` and `import_errors` are the two halves of
+    /// `resolve_schema_references`'s return value: the schemas that
+    /// resolved successfully, and the precise reason each one that didn't
+    /// failed (deployment unknown vs. deployed with no input schema).
     pub fn validate(
         &self,
         schemas: &HashMap<SchemaReference, Arc<Schema>>,
+        import_errors: &[SchemaImportError],
     ) -> Result<(), Vec<SchemaValidationError>> {
-        Ok(())
+        let mut errors = self.validate_federation_directives();
+        errors.extend(self.validate_auth_directives());
+        errors.extend(self.validate_interface_implementations());
+        errors.extend(self.validate_import_directives());
+        errors.extend(self.validate_imported_types(schemas, import_errors));
+        errors.extend(self.validate_pagination_directive());
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validate the `@key`, `@extends`, and `@external` directives used for
+    /// Apollo Federation entity resolution.
+    fn validate_federation_directives(&self) -> Vec<SchemaValidationError> {
+        self.document
+            .get_object_type_definitions()
+            .into_iter()
+            .filter_map(|obj_type| FederationKeyDefinition::parse(obj_type).err())
+            .collect()
+    }
+
+    /// Validate the field-level `@auth` authorization directive, alongside
+    /// the existing `@fulltext` validation group.
+    fn validate_auth_directives(&self) -> Vec<SchemaValidationError> {
+        match Schema::collect_auth_guards(&self.document) {
+            Ok(_) => vec![],
+            Err(e) => vec![e],
+        }
     }
 
     fn validate_schema_type_has_no_fields(&self) -> Result<(), SchemaValidationError> {
@@ -482,13 +1652,19 @@ impl Schema {
 
     /// Check the syntax of a single `@import` directive
     fn validate_import_directive_arguments(import: &Directive) -> Option<SchemaValidationError> {
-        None
+        match ImportDirective::parse(import) {
+            Some(_) => None,
+            None => Some(SchemaValidationError::ImportDirectiveMalformed),
+        }
     }
 
     fn validate_import_directive_schema_reference_parses(
         directive: &Directive,
     ) -> Option<SchemaValidationError> {
-        None
+        match directive.argument("from") {
+            Some(from) if SchemaReference::parse(from).is_some() => None,
+            _ => Some(SchemaValidationError::ImportDirectiveInvalidSchemaReference),
+        }
     }
 
     fn validate_fulltext_directives(&self) -> Vec<SchemaValidationError> {
@@ -521,14 +1697,54 @@ impl Schema {
     }
 
     fn validate_import_directives(&self) -> Vec<SchemaValidationError> {
-        vec![]
+        self.import_directives()
+            .into_iter()
+            .filter_map(|import| {
+                Self::validate_import_directive_arguments(import)
+                    .or_else(|| Self::validate_import_directive_schema_reference_parses(import))
+            })
+            .collect()
     }
 
     fn validate_imported_types(
         &self,
         schemas: &HashMap<SchemaReference, Arc<Schema>>,
+        import_errors: &[SchemaImportError],
     ) -> Vec<SchemaValidationError> {
-        vec![]
+        self.import_directives()
+            .into_iter()
+            .filter_map(|directive| {
+                let import = ImportDirective::parse(directive)?;
+                if schemas.contains_key(&import.schema_ref) {
+                    return None;
+                }
+
+                // Report the specific reason resolution failed — an
+                // unknown deployment (`ImportedSubgraphNotFound`) and a
+                // deployment with no recorded input schema
+                // (`ImportedSchemaNotFound`) are different operator-facing
+                // problems — falling back to `ImportedSchemaNotFound` only
+                // if `resolve_schema_references` was never run against
+                // this reference at all.
+                let error = import_errors
+                    .iter()
+                    .find(|e| match e {
+                        SchemaImportError::ImportedSchemaNotFound(r)
+                        | SchemaImportError::ImportedSubgraphNotFound(r) => {
+                            *r == import.schema_ref
+                        }
+                    })
+                    .cloned()
+                    .unwrap_or_else(|| {
+                        SchemaImportError::ImportedSchemaNotFound(import.schema_ref.clone())
+                    });
+
+                Some(SchemaValidationError::ImportedTypeUnresolved(
+                    error,
+                    directive.position,
+                ))
+            })
+            .collect()
     }
 
     fn validate_fields(&self) -> Vec<SchemaValidationError> {
@@ -549,20 +1765,130 @@ impl Schema {
         Ok(())
     }
 
-    /// Validate that `object` implements `interface`.
+    /// Validate that `object` implements `interface`: every field declared
+    /// on the interface must exist on the object with a covariant type.
     fn validate_interface_implementation(
+        &self,
         object: &ObjectType,
         interface: &InterfaceType,
     ) -> Result<(), SchemaValidationError> {
+        let entity_type = EntityType::from(object.name.as_str());
+        for iface_field in &interface.fields {
+            // A field declared by more than one implemented interface is
+            // only a problem when those interfaces disagree on its type;
+            // two interfaces both requiring `id: ID!`, say, is ordinary,
+            // valid GraphQL. `collect_interfaces` already did the work of
+            // telling the two cases apart, so surface its verdict here
+            // instead of re-deriving it.
+            if let Some(FieldOrigin::Conflict(interfaces)) = self
+                .field_origins
+                .get(&(entity_type.clone(), iface_field.name.clone()))
+            {
+                return Err(SchemaValidationError::InterfaceFieldConflict(
+                    object.name.clone(),
+                    iface_field.name.clone(),
+                    Strings(interfaces.clone()),
+                ));
+            }
+            match object.field(&iface_field.name) {
+                None => {
+                    return Err(SchemaValidationError::InterfaceFieldMissing(
+                        object.name.clone(),
+                        interface.name.clone(),
+                        iface_field.name.clone(),
+                    ))
+                }
+                Some(obj_field) => {
+                    if !is_valid_field_subtype(&obj_field.field_type, &iface_field.field_type) {
+                        return Err(SchemaValidationError::InterfaceFieldTypeMismatch(
+                            object.name.clone(),
+                            interface.name.clone(),
+                            iface_field.name.clone(),
+                        ));
+                    }
+                }
+            }
+        }
         Ok(())
     }
 
+    /// Run `validate_interface_implementation` for every object/interface
+    /// pair recorded in `interfaces_for_type`.
+    fn validate_interface_implementations(&self) -> Vec<SchemaValidationError> {
+        let mut errors = vec![];
+        for (entity_type, interfaces) in &self.interfaces_for_type {
+            let object = match self
+                .document
+                .get_object_type_definition(entity_type.as_str())
+            {
+                Some(object) => object,
+                None => continue,
+            };
+            for interface in interfaces {
+                if let Err(e) = self.validate_interface_implementation(object, interface) {
+                    errors.push(e);
+                }
+            }
+        }
+        errors
+    }
+
     fn validate_interface_id_type(&self) -> Result<(), SchemaValidationError> {
         Ok(())
     }
 
+    /// The dummy `_Schema_` type that carries schema-wide directives such
+    /// as `@import` and `@subgraphId`, if the document declares one.
     fn subgraph_schema_object_type(&self) -> Option<&ObjectType> {
-        None
+        self.document
+            .get_object_type_definitions()
+            .into_iter()
+            .find(|t| t.name == SCHEMA_TYPE_NAME)
+    }
+
+    /// Parse the `@pagination` directive on the `_Schema_` type, falling
+    /// back to the default (offset pagination disabled) when absent.
+    fn collect_pagination_config(&self) -> Result<PaginationConfig, SchemaValidationError> {
+        match self
+            .subgraph_schema_object_type()
+            .and_then(|t| t.directives.iter().find(|d| d.name == PAGINATION_DIRECTIVE))
+        {
+            Some(directive) => PaginationConfig::parse(directive),
+            None => Ok(PaginationConfig::default()),
+        }
+    }
+
+    fn validate_pagination_directive(&self) -> Vec<SchemaValidationError> {
+        match self.collect_pagination_config() {
+            Ok(_) => vec![],
+            Err(e) => vec![e],
+        }
+    }
+
+    /// Check that a `first`/`offset` pagination request on a collection
+    /// field stays within the bounds configured for this schema. `offset`
+    /// is only accepted at all when `@pagination(offset: true)` was set.
+    pub fn validate_pagination_window(&self, first: i32, offset: Option<i32>) -> Result<(), Error> {
+        if first < 0 || first as u32 > self.pagination.max_first {
+            return Err(anyhow!(
+                "`first` must be between 0 and {}, got {}",
+                self.pagination.max_first,
+                first
+            ));
+        }
+
+        if let Some(offset) = offset {
+            if !self.pagination.offset_enabled {
+                return Err(anyhow!(
+                    "`offset` pagination is not enabled for this schema"
+                ));
+            }
+            if offset < 0 {
+                return Err(anyhow!("`offset` must not be negative, got {}", offset));
+            }
+        }
+
+        Ok(())
     }
 
     pub fn entity_fulltext_definitions(